@@ -1,21 +1,31 @@
 //! This library provides a set of data structures for representing points in two and three dimensional space.
 //!
-//! The structures are named `Point2D` and `Point3D` respectively.
-//! They provide intuitive operations such as addition, subtraction, negation and comparison.
+//! The structures are named `Point2D` and `Point3D` respectively, with companion
+//! displacement types `Vector2D` and `Vector3D`.
+//! Following the affine geometry convention used by crates like `cgmath` and `euclid`,
+//! positions (`Point`s) and displacements (`Vector`s) are kept distinct: subtracting two
+//! points yields the `Vector` between them, and adding a `Vector` to a `Point` yields a
+//! new `Point`. Only `Vector`s are negatable and addable among themselves.
+//!
+//! Every type also carries an optional unit/space marker `U` (borrowed from `euclid`)
+//! that statically prevents mixing coordinates from different spaces — e.g. screen pixels
+//! and world meters. The marker defaults to [`UnknownUnit`], so untagged usage keeps
+//! compiling, and [`Point2D::cast_unit`] re-tags a value explicitly.
 
-use derive_more::{Add, AddAssign, Deref, DerefMut, From, Into, Neg, Sub, SubAssign};
-use num_traits::{Bounded, Num, CheckedMul, CheckedAdd};
+use derive_more::{Deref, DerefMut};
+use num_traits::{Bounded, Num, CheckedMul, CheckedAdd, CheckedSub, NumCast, ToPrimitive};
 use std::array;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// The default unit/space marker used for coordinates that are not tagged with a space.
+#[derive(Copy, Clone, Default, Hash, Eq, PartialEq, Debug)]
+pub struct UnknownUnit;
 
 macro_rules! derive_universal_traits {
     ($struct_def: item) => {
         // Constructors
         #[derive(Copy, Clone, Default)]
-        // std::ops – unary
-        #[derive(Neg)]
-        // std::ops – binary
-        #[derive(Add, AddAssign, Sub, SubAssign)]
         // Comparisons
         #[derive(Hash,Eq,PartialEq)]
         // Displayers
@@ -25,7 +35,7 @@ macro_rules! derive_universal_traits {
 }
 macro_rules! impl_hypot_sq{
     ($struct_id: ident, $first_operand: ident $(, $other: ident)*)=> {
-        impl<'l,N> $struct_id<N>  where N: Num +CheckedMul +CheckedAdd {
+        impl<'l,N,U> $struct_id<N,U>  where N: Num +CheckedMul +CheckedAdd {
             /// Returns the sum of squares of each coordinate.
             ///
             /// Performs computation using `N` type and its checked operations
@@ -39,12 +49,12 @@ macro_rules! impl_hypot_sq{
                 "```\n",
                 "use siiir_points::", stringify!($struct_id), ";\n",
                 "\n",
-                "let p= ", stringify!($struct_id), "::from( std::array::from_fn(|idx| idx) );\n",
+                "let v= ", stringify!($struct_id), "::<usize>::from( std::array::from_fn(|idx| idx) );\n",
                 "assert_eq!( ",
-                    "p.hypot_sq(), ",
+                    "v.hypot_sq(), ",
                     "Some( ",
-                        stringify!(p.$first_operand), "*", stringify!(p.$first_operand),
-                        $( " + ", stringify!(p.$other), "*", stringify!(p.$other), )*
+                        stringify!(v.$first_operand), "*", stringify!(v.$first_operand),
+                        $( " + ", stringify!(v.$other), "*", stringify!(v.$other), )*
                     " )",
                 " );\n",
                 "```\n",
@@ -65,7 +75,7 @@ macro_rules! impl_hypot_sq{
 }
 macro_rules! impl_bounds {
     ($id: ident) => {
-        impl<N: Num + Bounded> Bounded for $id<N> {
+        impl<N: Num + Bounded, U> Bounded for $id<N, U> {
             fn min_value() -> Self {
                 array::from_fn(|_| N::min_value()).into()
             }
@@ -76,57 +86,444 @@ macro_rules! impl_bounds {
         }
     };
 }
+/// Implements `cast_unit`, the explicit escape hatch for re-tagging a value's space.
+///
+/// The conversion goes through the array representation, so the coordinates are moved
+/// unchanged and only the `PhantomData` marker is replaced.
+macro_rules! impl_cast_unit {
+    ($id: ident, $k: literal) => {
+        impl<N: Num, U> $id<N, U> {
+            /// Reinterprets this value as belonging to a different unit/space `U2`.
+            ///
+            /// The coordinates are preserved verbatim; only the marker type changes.
+            pub fn cast_unit<U2>(self) -> $id<N, U2> {
+                let a: [N; $k] = self.into();
+                a.into()
+            }
+        }
+    };
+}
+/// Implements the displacement operators (`Neg`, `Add`, `Sub`, and their assign variants)
+/// for a vector type, requiring a matching unit `U` on both operands.
+macro_rules! impl_vector_ops {
+    ($vector: ident, $k: literal) => {
+        impl<N: Num + std::ops::Neg<Output = N>, U> std::ops::Neg for $vector<N, U> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                let a: [N; $k] = self.into();
+                let mut a = a.into_iter();
+                array::from_fn(|_| -a.next().unwrap()).into()
+            }
+        }
+        impl<N: Num, U> std::ops::Add for $vector<N, U> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = rhs.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| a.next().unwrap() + b.next().unwrap()).into()
+            }
+        }
+        impl<N: Num, U> std::ops::Sub for $vector<N, U> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = rhs.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| a.next().unwrap() - b.next().unwrap()).into()
+            }
+        }
+        impl<N: Num + Clone, U: Clone> std::ops::AddAssign for $vector<N, U> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = self.clone() + rhs;
+            }
+        }
+        impl<N: Num + Clone, U: Clone> std::ops::SubAssign for $vector<N, U> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = self.clone() - rhs;
+            }
+        }
+    };
+}
+/// Implements numeric-type casting of the coordinates between representations.
+///
+/// Each coordinate is routed through [`NumCast::from`]; [`try_cast`](Self::try_cast)
+/// returns `None` if any coordinate is not representable in the target type, while
+/// [`cast`](Self::cast) panics in that case. The unit/space marker is preserved.
+macro_rules! impl_num_cast {
+    ($id: ident, $k: literal) => {
+        impl<N: Num + ToPrimitive, U> $id<N, U> {
+            /// Casts the coordinates to numeric type `M`, returning `None` if any
+            /// coordinate cannot be represented in `M`.
+            pub fn try_cast<M: Num + NumCast>(self) -> Option<$id<M, U>> {
+                let a: [N; $k] = self.into();
+                let casted: [Option<M>; $k] = a.map(|n| M::from(n));
+                if casted.iter().any(Option::is_none) {
+                    return None;
+                }
+                let mut casted = casted.into_iter();
+                let out: [M; $k] = array::from_fn(|_| casted.next().unwrap().unwrap());
+                Some(out.into())
+            }
+
+            /// Casts the coordinates to numeric type `M`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any coordinate cannot be represented in `M`; use
+            /// [`try_cast`](Self::try_cast) for a fallible version.
+            pub fn cast<M: Num + NumCast>(self) -> $id<M, U> {
+                self.try_cast()
+                    .expect("coordinate not representable in the target numeric type")
+            }
+        }
+    };
+}
+/// Implements `serde::Serialize`/`Deserialize` behind the `serde` feature.
+///
+/// The value is (de)serialized through its `[N; $k]` array representation, matching
+/// `euclid`'s layout so a point round-trips as `[x, y]` / `[x, y, z]`. The unit marker
+/// carries no data and is reconstructed on deserialization.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde {
+    ($id: ident, $k: literal) => {
+        impl<N: Num + Clone + serde::Serialize, U: Clone> serde::Serialize for $id<N, U> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let a: [N; $k] = self.clone().into();
+                a.serialize(serializer)
+            }
+        }
+        impl<'de, N: Num + serde::Deserialize<'de>, U> serde::Deserialize<'de> for $id<N, U> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let a = <[N; $k]>::deserialize(deserializer)?;
+                Ok(a.into())
+            }
+        }
+    };
+}
+/// Implements the component-wise combinators shared by both dimensions.
+///
+/// These operate over the `[N; $k]` array representation so a single definition serves
+/// 2D and 3D. `map`/`zip` transform coordinates, `min`/`max` take the component-wise
+/// extremum (via [`approxord`]), and `lerp` interpolates between two float values.
+macro_rules! impl_combinators {
+    ($id: ident, $k: literal) => {
+        impl<N: Num, U> $id<N, U> {
+            /// Applies `f` to each coordinate, producing a value of the same shape over `M`.
+            pub fn map<M: Num, F: FnMut(N) -> M>(self, f: F) -> $id<M, U> {
+                let a: [N; $k] = self.into();
+                a.map(f).into()
+            }
+
+            /// Combines `self` and `other` coordinate-wise through `f`.
+            pub fn zip<O: Num, F: FnMut(N, N) -> O>(self, other: Self, mut f: F) -> $id<O, U> {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = other.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| f(a.next().unwrap(), b.next().unwrap())).into()
+            }
+
+            /// Returns the component-wise minimum of `self` and `other`.
+            pub fn min(self, other: Self) -> Self
+            where
+                N: PartialOrd,
+            {
+                self.zip(other, crate::approxord::min)
+            }
+
+            /// Returns the component-wise maximum of `self` and `other`.
+            pub fn max(self, other: Self) -> Self
+            where
+                N: PartialOrd,
+            {
+                self.zip(other, crate::approxord::max)
+            }
+
+            /// Linearly interpolates towards `other` by `t`, computing
+            /// `self + (other - self) * t` coordinate-wise.
+            pub fn lerp(self, other: Self, t: N) -> Self
+            where
+                N: num_traits::Float,
+            {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = other.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| {
+                    let ai = a.next().unwrap();
+                    let bi = b.next().unwrap();
+                    ai + (bi - ai) * t
+                })
+                .into()
+            }
+        }
+    };
+}
+/// Implements the scalar product operators (`Mul<N>`, `Div<N>`, and their assign variants)
+/// for a vector type, scaling every coordinate by the scalar.
+macro_rules! impl_vector_scalar_ops {
+    ($vector: ident, $k: literal) => {
+        impl<N: Num + Clone, U> std::ops::Mul<N> for $vector<N, U> {
+            type Output = Self;
+            fn mul(self, scalar: N) -> Self {
+                let a: [N; $k] = self.into();
+                let mut a = a.into_iter();
+                array::from_fn(|_| a.next().unwrap() * scalar.clone()).into()
+            }
+        }
+        impl<N: Num + Clone, U> std::ops::Div<N> for $vector<N, U> {
+            type Output = Self;
+            fn div(self, scalar: N) -> Self {
+                let a: [N; $k] = self.into();
+                let mut a = a.into_iter();
+                array::from_fn(|_| a.next().unwrap() / scalar.clone()).into()
+            }
+        }
+        impl<N: Num + Clone, U: Clone> std::ops::MulAssign<N> for $vector<N, U> {
+            fn mul_assign(&mut self, scalar: N) {
+                *self = self.clone() * scalar;
+            }
+        }
+        impl<N: Num + Clone, U: Clone> std::ops::DivAssign<N> for $vector<N, U> {
+            fn div_assign(&mut self, scalar: N) {
+                *self = self.clone() / scalar;
+            }
+        }
+    };
+}
+/// Implements the affine operators tying a point type to its companion vector type.
+///
+/// `Point - Point` yields the displacement `Vector` between them, while
+/// `Point + Vector` and `Point - Vector` translate a position. Every operator requires a
+/// matching unit `U` on both operands. The coordinate count `$k` drives the shared
+/// array-based implementation used by both dimensions.
+macro_rules! impl_affine_ops {
+    ($point: ident, $vector: ident, $k: literal) => {
+        impl<N: Num, U> std::ops::Sub for $point<N, U> {
+            type Output = $vector<N, U>;
+            fn sub(self, rhs: Self) -> $vector<N, U> {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = rhs.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| a.next().unwrap() - b.next().unwrap()).into()
+            }
+        }
+        impl<N: Num, U> std::ops::Add<$vector<N, U>> for $point<N, U> {
+            type Output = $point<N, U>;
+            fn add(self, rhs: $vector<N, U>) -> $point<N, U> {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = rhs.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| a.next().unwrap() + b.next().unwrap()).into()
+            }
+        }
+        impl<N: Num, U> std::ops::Sub<$vector<N, U>> for $point<N, U> {
+            type Output = $point<N, U>;
+            fn sub(self, rhs: $vector<N, U>) -> $point<N, U> {
+                let a: [N; $k] = self.into();
+                let b: [N; $k] = rhs.into();
+                let mut a = a.into_iter();
+                let mut b = b.into_iter();
+                array::from_fn(|_| a.next().unwrap() - b.next().unwrap()).into()
+            }
+        }
+        impl<N: Num + Clone, U: Clone> std::ops::AddAssign<$vector<N, U>> for $point<N, U> {
+            fn add_assign(&mut self, rhs: $vector<N, U>) {
+                *self = self.clone() + rhs;
+            }
+        }
+        impl<N: Num + Clone, U: Clone> std::ops::SubAssign<$vector<N, U>> for $point<N, U> {
+            fn sub_assign(&mut self, rhs: $vector<N, U>) {
+                *self = self.clone() - rhs;
+            }
+        }
+        impl<N: Num, U> $point<N, U> {
+            /// Reinterprets this position as the displacement from the origin.
+            ///
+            /// This is the escape hatch for the permissive, pre-affine behaviour where
+            /// points and vectors were interchangeable.
+            pub fn to_vector(self) -> $vector<N, U> {
+                let a: [N; $k] = self.into();
+                a.into()
+            }
+
+            /// Returns the squared distance between `self` and `other`.
+            ///
+            /// Equivalent to `(self - other).hypot_sq()`; see
+            #[doc= concat!("[`", stringify!($vector), "::hypot_sq`].")]
+            pub fn distance_sq(&self, other: &Self) -> Option<N>
+            where
+                N: CheckedMul + CheckedAdd + Clone,
+                U: Clone,
+            {
+                (self.clone() - other.clone()).hypot_sq()
+            }
+        }
+        impl<N: Num, U> $vector<N, U> {
+            /// Reinterprets this displacement as a position relative to the origin.
+            ///
+            /// This is the escape hatch for the permissive, pre-affine behaviour where
+            /// points and vectors were interchangeable.
+            pub fn to_point(self) -> $point<N, U> {
+                let a: [N; $k] = self.into();
+                a.into()
+            }
+        }
+    };
+}
 
 // Point2D
 derive_universal_traits! {
-    // Conversions
-    #[derive(From, Into)]
     /// `Point2D` structure represents a point in two dimensional space.
     ///
     /// It is a generic structure, so it can be used with any type that implements the `num::Num` trait.
+    /// The `U` parameter tags the coordinate space and defaults to [`UnknownUnit`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use siiir_points::Point2D;
+    /// use siiir_points::{Point2D, Vector2D};
     ///
     /// let p1 = Point2D::<f32>::from((2.0, 3.0));
     /// assert_eq!(&p1, &p1);
     ///
     /// let p2: Point2D::<f32> = (4.0, 6.0).into();
-    /// assert_eq!(p2, p2);
-    ///
-    /// let p3 = p1 + p2;
-    /// assert_eq!( p3, Point2D::from((6.0, 9.0) ) );
     ///
-    /// let p4 = p3 - p1;
-    /// assert_eq!( p4, Point2D::from((4.0, 6.0)) );
+    /// // Subtracting two points yields the displacement between them.
+    /// let d = p2 - p1;
+    /// assert_eq!( d, Vector2D::from((2.0, 3.0)) );
     ///
-    /// let p5 = -p4;
-    /// assert_eq!( p5, Point2D::from((-4.0, -6.0)) );
+    /// // Adding a displacement to a point yields a new point.
+    /// let p3 = p1 + d;
+    /// assert_eq!( p3, p2 );
     /// ```
-    pub struct Point2D<N: Num>{
+    pub struct Point2D<N: Num, U = UnknownUnit>{
         pub x: N,
         pub y: N,
+        _unit: PhantomData<U>,
     }
 }
-impl_hypot_sq!(Point2D, x, y);
-impl<N: Num> From<[N; 2]> for Point2D<N> {
+impl<N: Num, U> From<[N; 2]> for Point2D<N, U> {
     fn from([x, y]: [N; 2]) -> Self {
-        Self { x, y }
+        Self { x, y, _unit: PhantomData }
     }
 }
-impl<N: Num> From<Point2D<N>> for [N; 2] {
-    fn from(Point2D { x, y }: Point2D<N>) -> Self {
+impl<N: Num, U> From<Point2D<N, U>> for [N; 2] {
+    fn from(Point2D { x, y, .. }: Point2D<N, U>) -> Self {
         [x, y]
     }
 }
-impl<N: Num + Display> Display for Point2D<N> {
+impl<N: Num, U> From<(N, N)> for Point2D<N, U> {
+    fn from((x, y): (N, N)) -> Self {
+        Self { x, y, _unit: PhantomData }
+    }
+}
+impl<N: Num, U> From<Point2D<N, U>> for (N, N) {
+    fn from(Point2D { x, y, .. }: Point2D<N, U>) -> Self {
+        (x, y)
+    }
+}
+impl<N: Num + Display, U> Display for Point2D<N, U> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "( {}, {} )", self.x, self.y)
     }
 }
 impl_bounds!(Point2D);
+impl_cast_unit!(Point2D, 2);
+impl_num_cast!(Point2D, 2);
+impl_combinators!(Point2D, 2);
+#[cfg(feature = "serde")]
+impl_serde!(Point2D, 2);
+impl_affine_ops!(Point2D, Vector2D, 2);
+
+// Vector2D
+derive_universal_traits! {
+    /// `Vector2D` is the displacement companion of [`Point2D`].
+    ///
+    /// It shares `Point2D`'s field layout but models a direction/offset rather than a
+    /// position, so unlike points vectors may be added together and negated. Like points
+    /// it carries a unit/space marker `U` defaulting to [`UnknownUnit`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use siiir_points::Vector2D;
+    ///
+    /// let v1 = Vector2D::<f32>::from((2.0, 3.0));
+    /// let v2: Vector2D::<f32> = (4.0, 6.0).into();
+    ///
+    /// let v3 = v1 + v2;
+    /// assert_eq!( v3, Vector2D::from((6.0, 9.0)) );
+    ///
+    /// let v4 = -v3;
+    /// assert_eq!( v4, Vector2D::from((-6.0, -9.0)) );
+    /// ```
+    pub struct Vector2D<N: Num, U = UnknownUnit>{
+        pub x: N,
+        pub y: N,
+        _unit: PhantomData<U>,
+    }
+}
+impl_hypot_sq!(Vector2D, x, y);
+impl<N: Num, U> From<[N; 2]> for Vector2D<N, U> {
+    fn from([x, y]: [N; 2]) -> Self {
+        Self { x, y, _unit: PhantomData }
+    }
+}
+impl<N: Num, U> From<Vector2D<N, U>> for [N; 2] {
+    fn from(Vector2D { x, y, .. }: Vector2D<N, U>) -> Self {
+        [x, y]
+    }
+}
+impl<N: Num, U> From<(N, N)> for Vector2D<N, U> {
+    fn from((x, y): (N, N)) -> Self {
+        Self { x, y, _unit: PhantomData }
+    }
+}
+impl<N: Num, U> From<Vector2D<N, U>> for (N, N) {
+    fn from(Vector2D { x, y, .. }: Vector2D<N, U>) -> Self {
+        (x, y)
+    }
+}
+impl<N: Num + Display, U> Display for Vector2D<N, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "( {}, {} )", self.x, self.y)
+    }
+}
+impl_bounds!(Vector2D);
+impl_cast_unit!(Vector2D, 2);
+impl_num_cast!(Vector2D, 2);
+impl_combinators!(Vector2D, 2);
+#[cfg(feature = "serde")]
+impl_serde!(Vector2D, 2);
+impl_vector_ops!(Vector2D, 2);
+impl_vector_scalar_ops!(Vector2D, 2);
+impl<N: Num, U> Vector2D<N, U> {
+    /// Returns the dot product of `self` and `other`, i.e.
+    /// `self.x*other.x + self.y*other.y`.
+    pub fn dot(&self, other: &Self) -> N
+    where
+        N: Clone,
+    {
+        self.x.clone() * other.x.clone() + self.y.clone() * other.y.clone()
+    }
+
+    /// Like [`dot`](Self::dot) but computed with the `CheckedMul`/`CheckedAdd`
+    /// operations, returning `None` on overflow.
+    pub fn checked_dot(&self, other: &Self) -> Option<N>
+    where
+        N: CheckedMul + CheckedAdd,
+    {
+        self.x
+            .checked_mul(&other.x)?
+            .checked_add(&self.y.checked_mul(&other.y)?)
+    }
+}
 
 // Point3D
 derive_universal_traits! {
@@ -135,36 +532,34 @@ derive_universal_traits! {
     /// `Point3D` structure represents a point in three dimensional space.
     ///
     /// It is also a generic structure and can be used with any type that implements the `num::Num` trait.
+    /// The unit/space marker `U` is threaded through the nested [`Point2D`] and defaults to [`UnknownUnit`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use siiir_points::Point3D;
+    /// use siiir_points::{Point3D, Vector3D};
     ///
     /// let p1: Point3D<_> = (2.0, 3.0, 4.0).into();
     /// assert_eq!(&p1, &p1);
     ///
     /// let p2: Point3D<_> = (4.0, 6.0, 8.0).into();
-    /// assert_eq!(&p2, &p2);
-    ///
-    /// let p3 = p1 + p2;
-    /// assert_eq!(p3, Point3D::from((6.0, 9.0, 12.0)));
     ///
-    /// let p4 = p3 - p1;
-    /// assert_eq!(p4, Point3D::from((4.0, 6.0, 8.0)));
+    /// // Subtracting two points yields the displacement between them.
+    /// let d = p2 - p1;
+    /// assert_eq!(d, Vector3D::from((2.0, 3.0, 4.0)));
     ///
-    /// let p5 = -p4;
-    /// assert_eq!(p5, Point3D::from((-4.0, -6.0, -8.0)));
+    /// // Adding a displacement to a point yields a new point.
+    /// let p3 = p1 + d;
+    /// assert_eq!(p3, p2);
     /// ```
-    pub struct Point3D<N: Num>{
+    pub struct Point3D<N: Num, U = UnknownUnit>{
         #[deref]
         #[deref_mut]
-        pub xy: Point2D<N>,
+        pub xy: Point2D<N, U>,
         pub z: N,
     }
 }
-impl_hypot_sq!(Point3D, x, y, z);
-impl<N: Num> From<[N; 3]> for Point3D<N> {
+impl<N: Num, U> From<[N; 3]> for Point3D<N, U> {
     fn from([x, y, z]: [N; 3]) -> Self {
         Self {
             xy: [x, y].into(),
@@ -172,32 +567,257 @@ impl<N: Num> From<[N; 3]> for Point3D<N> {
         }
     }
 }
-impl<N: Num> From<Point3D<N>> for [N; 3] {
-    fn from(value: Point3D<N>) -> Self {
+impl<N: Num, U> From<Point3D<N, U>> for [N; 3] {
+    fn from(value: Point3D<N, U>) -> Self {
         let Point3D {
-            xy: Point2D { x, y },
+            xy: Point2D { x, y, .. },
             z,
         } = value;
         [x, y, z]
     }
 }
-impl<N: Num> From<(N, N, N)> for Point3D<N> {
+impl<N: Num, U> From<(N, N, N)> for Point3D<N, U> {
     fn from((x, y, z): (N, N, N)) -> Self {
         [x, y, z].into()
     }
 }
-impl<N: Num> From<Point3D<N>> for (N, N, N) {
-    fn from(value: Point3D<N>) -> Self {
+impl<N: Num, U> From<Point3D<N, U>> for (N, N, N) {
+    fn from(value: Point3D<N, U>) -> Self {
         let [x, y, z]: [N; 3] = value.into();
         (x, y, z)
     }
 }
-impl<N: Num + Display> Display for Point3D<N> {
+impl<N: Num + Display, U> Display for Point3D<N, U> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "( {}, {}, {} )", self.x, self.y, self.z)
     }
 }
 impl_bounds!(Point3D);
+impl_cast_unit!(Point3D, 3);
+impl_num_cast!(Point3D, 3);
+impl_combinators!(Point3D, 3);
+#[cfg(feature = "serde")]
+impl_serde!(Point3D, 3);
+impl_affine_ops!(Point3D, Vector3D, 3);
+
+// Vector3D
+derive_universal_traits! {
+    // Dereference
+    #[derive(Deref,DerefMut)]
+    /// `Vector3D` is the displacement companion of [`Point3D`].
+    ///
+    /// Like [`Vector2D`] it models a direction/offset rather than a position, so vectors
+    /// may be added together and negated while points may not. The unit/space marker `U`
+    /// is threaded through the nested [`Vector2D`] and defaults to [`UnknownUnit`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use siiir_points::Vector3D;
+    ///
+    /// let v1: Vector3D<_> = (2.0, 3.0, 4.0).into();
+    /// let v2: Vector3D<_> = (4.0, 6.0, 8.0).into();
+    ///
+    /// let v3 = v1 + v2;
+    /// assert_eq!(v3, Vector3D::from((6.0, 9.0, 12.0)));
+    ///
+    /// let v4 = -v3;
+    /// assert_eq!(v4, Vector3D::from((-6.0, -9.0, -12.0)));
+    /// ```
+    pub struct Vector3D<N: Num, U = UnknownUnit>{
+        #[deref]
+        #[deref_mut]
+        pub xy: Vector2D<N, U>,
+        pub z: N,
+    }
+}
+impl_hypot_sq!(Vector3D, x, y, z);
+impl<N: Num, U> From<[N; 3]> for Vector3D<N, U> {
+    fn from([x, y, z]: [N; 3]) -> Self {
+        Self {
+            xy: [x, y].into(),
+            z,
+        }
+    }
+}
+impl<N: Num, U> From<Vector3D<N, U>> for [N; 3] {
+    fn from(value: Vector3D<N, U>) -> Self {
+        let Vector3D {
+            xy: Vector2D { x, y, .. },
+            z,
+        } = value;
+        [x, y, z]
+    }
+}
+impl<N: Num, U> From<(N, N, N)> for Vector3D<N, U> {
+    fn from((x, y, z): (N, N, N)) -> Self {
+        [x, y, z].into()
+    }
+}
+impl<N: Num, U> From<Vector3D<N, U>> for (N, N, N) {
+    fn from(value: Vector3D<N, U>) -> Self {
+        let [x, y, z]: [N; 3] = value.into();
+        (x, y, z)
+    }
+}
+impl<N: Num + Display, U> Display for Vector3D<N, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "( {}, {}, {} )", self.x, self.y, self.z)
+    }
+}
+impl_bounds!(Vector3D);
+impl_cast_unit!(Vector3D, 3);
+impl_num_cast!(Vector3D, 3);
+impl_combinators!(Vector3D, 3);
+#[cfg(feature = "serde")]
+impl_serde!(Vector3D, 3);
+impl_vector_ops!(Vector3D, 3);
+impl_vector_scalar_ops!(Vector3D, 3);
+impl<N: Num, U> Vector3D<N, U> {
+    /// Returns the dot product of `self` and `other`, i.e.
+    /// `self.x*other.x + self.y*other.y + self.z*other.z`.
+    pub fn dot(&self, other: &Self) -> N
+    where
+        N: Clone,
+    {
+        self.x.clone() * other.x.clone()
+            + self.y.clone() * other.y.clone()
+            + self.z.clone() * other.z.clone()
+    }
+
+    /// Returns the cross product `self × other`, the vector perpendicular to both.
+    pub fn cross(&self, other: &Self) -> Vector3D<N, U>
+    where
+        N: Clone,
+    {
+        [
+            self.y.clone() * other.z.clone() - self.z.clone() * other.y.clone(),
+            self.z.clone() * other.x.clone() - self.x.clone() * other.z.clone(),
+            self.x.clone() * other.y.clone() - self.y.clone() * other.x.clone(),
+        ]
+        .into()
+    }
+
+    /// Like [`dot`](Self::dot) but computed with the `CheckedMul`/`CheckedAdd`
+    /// operations, returning `None` on overflow.
+    pub fn checked_dot(&self, other: &Self) -> Option<N>
+    where
+        N: CheckedMul + CheckedAdd,
+    {
+        self.x
+            .checked_mul(&other.x)?
+            .checked_add(&self.y.checked_mul(&other.y)?)?
+            .checked_add(&self.z.checked_mul(&other.z)?)
+    }
+
+    /// Like [`cross`](Self::cross) but computed with the `CheckedMul`/`CheckedSub`
+    /// operations, returning `None` on overflow.
+    pub fn checked_cross(&self, other: &Self) -> Option<Vector3D<N, U>>
+    where
+        N: CheckedMul + CheckedSub,
+    {
+        let x = self
+            .y
+            .checked_mul(&other.z)?
+            .checked_sub(&self.z.checked_mul(&other.y)?)?;
+        let y = self
+            .z
+            .checked_mul(&other.x)?
+            .checked_sub(&self.x.checked_mul(&other.z)?)?;
+        let z = self
+            .x
+            .checked_mul(&other.y)?
+            .checked_sub(&self.y.checked_mul(&other.x)?)?;
+        Some([x, y, z].into())
+    }
+}
+
+/// Partial-ordering helpers used by the component-wise `min`/`max` combinators.
+///
+/// Following `euclid`'s `approxord` module, these compare with `PartialOrd` so they work
+/// for floating-point coordinates, favouring the first argument when the two are
+/// incomparable (e.g. `NaN`).
+pub mod approxord {
+    /// Returns the lesser of `a` and `b`, favouring `a` when they are incomparable.
+    pub fn min<T: PartialOrd>(a: T, b: T) -> T {
+        if b < a {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// Returns the greater of `a` and `b`, favouring `a` when they are incomparable.
+    pub fn max<T: PartialOrd>(a: T, b: T) -> T {
+        if b > a {
+            b
+        } else {
+            a
+        }
+    }
+}
+
+/// Approximate equality for floating-point coordinates.
+///
+/// Comparing `f32`/`f64` points with the derived `PartialEq` is fragile because rounding
+/// makes exact `==` meaningless. Following `euclid`'s `approxeq` module, this trait
+/// compares each coordinate within a tolerance.
+pub mod approxeq {
+    use super::{Point2D, Point3D};
+    use num_traits::Float;
+
+    /// Trait for approximate equality comparisons within a tolerance of type `Eps`.
+    pub trait ApproxEq<Eps = Self> {
+        /// The default tolerance used by [`approx_eq`](Self::approx_eq).
+        fn approx_epsilon() -> Eps;
+
+        /// Returns `true` if `self` and `other` are equal within `approx_epsilon`.
+        fn approx_eq_eps(&self, other: &Self, approx_epsilon: &Eps) -> bool;
+
+        /// Returns `true` if `self` and `other` are equal within the default tolerance.
+        fn approx_eq(&self, other: &Self) -> bool {
+            self.approx_eq_eps(other, &Self::approx_epsilon())
+        }
+    }
+
+    macro_rules! impl_approx_eq_float {
+        ($float: ty, $eps: expr) => {
+            impl ApproxEq<$float> for $float {
+                fn approx_epsilon() -> $float {
+                    $eps
+                }
+                fn approx_eq_eps(&self, other: &Self, approx_epsilon: &Self) -> bool {
+                    (*self - *other).abs() <= *approx_epsilon
+                }
+            }
+        };
+    }
+    // Constant defaults borrowed from `euclid`, deliberately looser than machine epsilon so
+    // accumulated rounding still compares equal.
+    impl_approx_eq_float!(f32, 1.0e-6);
+    impl_approx_eq_float!(f64, 1.0e-12);
+
+    impl<N: Float + ApproxEq<N>, U> ApproxEq<N> for Point2D<N, U> {
+        fn approx_epsilon() -> N {
+            N::approx_epsilon()
+        }
+        fn approx_eq_eps(&self, other: &Self, approx_epsilon: &N) -> bool {
+            (self.x - other.x).abs() <= *approx_epsilon
+                && (self.y - other.y).abs() <= *approx_epsilon
+        }
+    }
+
+    impl<N: Float + ApproxEq<N>, U> ApproxEq<N> for Point3D<N, U> {
+        fn approx_epsilon() -> N {
+            N::approx_epsilon()
+        }
+        fn approx_eq_eps(&self, other: &Self, approx_epsilon: &N) -> bool {
+            (self.x - other.x).abs() <= *approx_epsilon
+                && (self.y - other.y).abs() <= *approx_epsilon
+                && (self.z - other.z).abs() <= *approx_epsilon
+        }
+    }
+}
 
 #[cfg(test)]
-mod test;
\ No newline at end of file
+mod test;
@@ -1,57 +1,81 @@
 mod from_text_davinci {
-    use crate::{Point2D, Point3D};
+    use crate::{Point2D, Point3D, Vector2D, Vector3D};
     use num_traits::Bounded;
 
     // Point2D
 
     #[test]
-    fn point2d_add() {
-        let p1 = Point2D::from([1, 2]);
-        let p2 = Point2D::from([3, 4]);
-        let p3 = p1 + p2;
-        assert_eq!(p3, Point2D::from([4, 6]));
+    fn point2d_translate() {
+        let p1: Point2D<i32> = Point2D::from([1, 2]);
+        let v = Vector2D::from([3, 4]);
+        let p2 = p1 + v;
+        assert_eq!(p2, Point2D::from([4, 6]));
     }
 
     #[test]
     fn point2d_add_assign() {
-        let mut p1 = Point2D::from([1, 2]);
-        let p2 = Point2D::from([3, 4]);
-        p1 += p2;
+        let mut p1: Point2D<i32> = Point2D::from([1, 2]);
+        let v = Vector2D::from([3, 4]);
+        p1 += v;
         assert_eq!(p1, Point2D::from([4, 6]));
     }
 
     #[test]
     fn point2d_sub() {
-        let p1 = Point2D::from([1, 2]);
+        let p1: Point2D<i32> = Point2D::from([1, 2]);
         let p2 = Point2D::from([3, 4]);
-        let p3 = p2 - p1;
-        assert_eq!(p3, Point2D::from([2, 2]));
+        let d = p2 - p1;
+        assert_eq!(d, Vector2D::from([2, 2]));
     }
 
     #[test]
     fn point2d_sub_assign() {
-        let p1 = Point2D::from([1, 2]);
-        let mut p2 = Point2D::from([3, 4]);
-        p2 -= p1;
-        assert_eq!(p2, Point2D::from([2, 2]));
+        let mut p1: Point2D<i32> = Point2D::from([3, 4]);
+        let v = Vector2D::from([1, 2]);
+        p1 -= v;
+        assert_eq!(p1, Point2D::from([2, 2]));
+    }
+
+    #[test]
+    fn point2d_to_vector() {
+        let p1: Point2D<i32> = Point2D::from([1, -2]);
+        assert_eq!(p1.to_vector(), Vector2D::from([1, -2]));
+    }
+
+    #[test]
+    fn point2d_distance_sq() {
+        let p1: Point2D<i32> = Point2D::from([1, 2]);
+        let p2 = Point2D::from([4, 6]);
+        assert_eq!(p1.distance_sq(&p2), Some(3 * 3 + 4 * 4));
+    }
+
+    #[test]
+    fn point2d_cast_unit() {
+        struct Meters;
+        struct Pixels;
+        let in_meters = Point2D::<i32, Meters>::from([1, 2]);
+        let in_pixels: Point2D<i32, Pixels> = in_meters.cast_unit();
+        let arr: [i32; 2] = in_pixels.into();
+        assert_eq!(arr, [1, 2]);
     }
 
     #[test]
-    fn point2d_neg() {
-        let p1 = Point2D::from([1, -2]);
-        let p2 = -p1;
-        assert_eq!(p2, Point2D::from([-1, 2]));
+    fn point2d_cast() {
+        let p: Point2D<i32> = Point2D::from([1, 2]);
+        let f: Point2D<f64> = p.cast();
+        assert_eq!(f, Point2D::from([1.0, 2.0]));
+        assert_eq!(Point2D::<i32>::from([300, 2]).try_cast::<u8>(), None);
     }
 
     #[test]
     fn point2d_display() {
-        let p1 = Point2D::from([1, -2]);
+        let p1: Point2D<i32> = Point2D::from([1, -2]);
         assert_eq!(format!("{}", p1), "( 1, -2 )");
     }
 
     #[test]
     fn point2d_eq() {
-        let p1 = Point2D::from([1, 2]);
+        let p1: Point2D<i32> = Point2D::from([1, 2]);
         let p2 = Point2D::from([3, 4]);
         let p3 = Point2D::from([2, 2]);
         assert_ne!(p1, p2);
@@ -60,14 +84,14 @@ mod from_text_davinci {
 
     #[test]
     fn point2d_from_arr() {
-        let p1 = Point2D::from([1, -2]);
+        let p1: Point2D<i32> = Point2D::from([1, -2]);
         let arr = [1, -2];
         assert_eq!(p1, Point2D::from(arr));
     }
 
     #[test]
     fn point2d_into_arr() {
-        let p1 = Point2D { x: 1, y: -2 };
+        let p1: Point2D<i32> = Point2D::from([1, -2]);
         let lhs_arr = [1, -2];
         let rhs_arr: [i32; 2] = p1.into();
         assert_eq!(lhs_arr, rhs_arr);
@@ -75,76 +99,185 @@ mod from_text_davinci {
 
     #[test]
     fn point2d_from_tuple() {
-        let p1 = Point2D::from((1, -2));
+        let p1: Point2D<i32> = Point2D::from((1, -2));
         let tup = (1, -2);
         assert_eq!(p1, Point2D::from(tup));
     }
 
     #[test]
     fn point2d_into_tuple() {
-        let p1 = Point2D::from((1, -2));
+        let p1: Point2D<i32> = Point2D::from((1, -2));
         let tup = (1, -2);
         assert_eq!(tup, p1.into());
     }
 
     #[test]
     fn point2d_bounds() {
-        let p1 = Point2D::min_value();
-        let p2 = Point2D::max_value();
+        let p1: Point2D<i32> = Point2D::min_value();
+        let p2: Point2D<f64> = Point2D::max_value();
         assert_eq!(p1, Point2D::from([i32::MIN, i32::MIN]));
         assert_eq!(p2, Point2D::from([f64::MAX, f64::MAX]));
     }
 
+    #[test]
+    fn point2d_approx_eq() {
+        use crate::approxeq::ApproxEq;
+        let p1: Point2D<f64> = Point2D::from([1.0_f64, 2.0]);
+        let p2 = Point2D::from([1.0 + 1e-18, 2.0 - 1e-18]);
+        assert!(p1.approx_eq(&p2));
+        // Accumulated rounding at non-unit magnitude stays within the default tolerance,
+        // which machine epsilon would have rejected.
+        let big: Point2D<f64> = Point2D::from([1000.0_f64, 2000.0]);
+        assert!(big.approx_eq(&Point2D::from([1000.0 + 2e-13, 2000.0 - 3e-13])));
+        // A discrepancy larger than the default tolerance is still rejected.
+        assert!(!big.approx_eq(&Point2D::from([1000.0 + 1e-9, 2000.0])));
+        assert!(!p1.approx_eq(&Point2D::from([1.1, 2.0])));
+        assert!(p1.approx_eq_eps(&Point2D::from([1.05, 2.0]), &0.1));
+    }
+
+    #[test]
+    fn point2d_map() {
+        let p: Point2D<i32> = Point2D::from([1, 2]);
+        assert_eq!(p.map(|n| n * 10), Point2D::from([10, 20]));
+    }
+
+    #[test]
+    fn point2d_zip() {
+        let p1: Point2D<i32> = Point2D::from([1, 2]);
+        let p2 = Point2D::from([3, 4]);
+        assert_eq!(p1.zip(p2, |a, b| a + b), Point2D::from([4, 6]));
+    }
+
+    #[test]
+    fn point2d_min_max() {
+        let p1: Point2D<i32> = Point2D::from([1, 5]);
+        let p2 = Point2D::from([3, 2]);
+        assert_eq!(p1.min(p2), Point2D::from([1, 2]));
+        assert_eq!(p1.max(p2), Point2D::from([3, 5]));
+    }
+
+    #[test]
+    fn point2d_lerp() {
+        let p1: Point2D<f64> = Point2D::from([0.0_f64, 0.0]);
+        let p2 = Point2D::from([10.0, 20.0]);
+        assert_eq!(p1.lerp(p2, 0.5), Point2D::from([5.0, 10.0]));
+    }
+
+    // Vector2D
+
+    #[test]
+    fn vector2d_add() {
+        let v1: Vector2D<i32> = Vector2D::from([1, 2]);
+        let v2 = Vector2D::from([3, 4]);
+        let v3 = v1 + v2;
+        assert_eq!(v3, Vector2D::from([4, 6]));
+    }
+
+    #[test]
+    fn vector2d_neg() {
+        let v1: Vector2D<i32> = Vector2D::from([1, -2]);
+        let v2 = -v1;
+        assert_eq!(v2, Vector2D::from([-1, 2]));
+    }
+
+    #[test]
+    fn vector2d_hypot_sq() {
+        let v: Vector2D<i32> = Vector2D::from([3, 4]);
+        assert_eq!(v.hypot_sq(), Some(25));
+    }
+
+    #[test]
+    fn vector2d_to_point() {
+        let v: Vector2D<i32> = Vector2D::from([1, -2]);
+        assert_eq!(v.to_point(), Point2D::from([1, -2]));
+    }
+
+    #[test]
+    fn vector2d_mul_scalar() {
+        let v: Vector2D<i32> = Vector2D::from([1, -2]);
+        assert_eq!(v * 3, Vector2D::from([3, -6]));
+    }
+
+    #[test]
+    fn vector2d_div_scalar() {
+        let v: Vector2D<i32> = Vector2D::from([3, -6]);
+        assert_eq!(v / 3, Vector2D::from([1, -2]));
+    }
+
+    #[test]
+    fn vector2d_mul_assign() {
+        let mut v: Vector2D<i32> = Vector2D::from([1, -2]);
+        v *= 3;
+        assert_eq!(v, Vector2D::from([3, -6]));
+    }
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn vector2d_dot() {
+        let v1: Vector2D<i32> = Vector2D::from([1, 2]);
+        let v2 = Vector2D::from([3, 4]);
+        assert_eq!(v1.dot(&v2), 1 * 3 + 2 * 4);
+        assert_eq!(v1.checked_dot(&v2), Some(11));
+    }
+
     // Point3D
 
     #[test]
-    fn point3d_add() {
-        let p1 = Point3D::from([1, 2, 3]);
-        let p2 = Point3D::from([3, 4, 5]);
-        let p3 = p1 + p2;
-        assert_eq!(p3, Point3D::from([4, 6, 8]));
+    fn point3d_translate() {
+        let p1: Point3D<i32> = Point3D::from([1, 2, 3]);
+        let v = Vector3D::from([3, 4, 5]);
+        let p2 = p1 + v;
+        assert_eq!(p2, Point3D::from([4, 6, 8]));
     }
 
     #[test]
     fn point3d_add_assign() {
-        let mut p1 = Point3D::from([1, 2, 3]);
-        let p2 = Point3D::from([3, 4, 5]);
-        p1 += p2;
+        let mut p1: Point3D<i32> = Point3D::from([1, 2, 3]);
+        let v = Vector3D::from([3, 4, 5]);
+        p1 += v;
         assert_eq!(p1, Point3D::from([4, 6, 8]));
     }
 
     #[test]
     fn point3d_sub() {
-        let p1 = Point3D::from([1, 2, 3]);
+        let p1: Point3D<i32> = Point3D::from([1, 2, 3]);
         let p2 = Point3D::from([3, 4, 5]);
-        let p3 = p2 - p1;
-        assert_eq!(p3, Point3D::from([2, 2, 2]));
+        let d = p2 - p1;
+        assert_eq!(d, Vector3D::from([2, 2, 2]));
     }
 
     #[test]
     fn point3d_sub_assign() {
-        let p1 = Point3D::from([1, 2, 3]);
-        let mut p2 = Point3D::from([3, 4, 5]);
-        p2 -= p1;
-        assert_eq!(p2, Point3D::from([2, 2, 2]));
+        let mut p1: Point3D<i32> = Point3D::from([3, 4, 5]);
+        let v = Vector3D::from([1, 2, 3]);
+        p1 -= v;
+        assert_eq!(p1, Point3D::from([2, 2, 2]));
     }
 
     #[test]
-    fn point3d_neg() {
-        let p1 = Point3D::from([1, -2, 0]);
-        let p2 = -p1;
-        assert_eq!(p2, Point3D::from([-1, 2, 0]));
+    fn point3d_distance_sq() {
+        let p1: Point3D<i32> = Point3D::from([1, 2, 3]);
+        let p2 = Point3D::from([3, 4, 5]);
+        assert_eq!(p1.distance_sq(&p2), Some(2 * 2 + 2 * 2 + 2 * 2));
+    }
+
+    #[test]
+    fn point3d_cast() {
+        let p: Point3D<i32> = Point3D::from([1, 2, 3]);
+        let f: Point3D<f64> = p.cast();
+        assert_eq!(f, Point3D::from([1.0, 2.0, 3.0]));
+        assert_eq!(Point3D::<i32>::from([1, 2, -3]).try_cast::<u8>(), None);
     }
 
     #[test]
     fn point3d_display() {
-        let p1 = Point3D::from([1, -2, 0]);
+        let p1: Point3D<i32> = Point3D::from([1, -2, 0]);
         assert_eq!(format!("{}", p1), "( 1, -2, 0 )");
     }
 
     #[test]
     fn point3d_eq() {
-        let p1 = Point3D::from([1, 2, 3]);
+        let p1: Point3D<i32> = Point3D::from([1, 2, 3]);
         let p2 = Point3D::from([3, 4, 5]);
         let p3 = Point3D::from([1, 2, 3]);
         assert_ne!(p1, p2);
@@ -153,14 +286,14 @@ mod from_text_davinci {
 
     #[test]
     fn point3d_from_arr() {
-        let p1 = Point3D::from([1, -2, 3]);
+        let p1: Point3D<i32> = Point3D::from([1, -2, 3]);
         let arr = [1, -2, 3];
         assert_eq!(p1, Point3D::from(arr));
     }
 
     #[test]
     fn point3d_into_arr() {
-        let p1 = Point3D {
+        let p1: Point3D<i32> = Point3D {
             xy: [1, -2].into(),
             z: 3,
         };
@@ -171,15 +304,117 @@ mod from_text_davinci {
 
     #[test]
     fn point3d_from_tuple() {
-        let p1 = Point3D::from((1, -2, 3));
+        let p1: Point3D<i32> = Point3D::from((1, -2, 3));
         let tup = (1, -2, 3);
         assert_eq!(p1, Point3D::from(tup));
     }
 
     #[test]
     fn point3d_into_tuple() {
-        let p1 = Point3D::from((1, -2, 3));
+        let p1: Point3D<i32> = Point3D::from((1, -2, 3));
         let tup = (1, -2, 3);
         assert_eq!(tup, p1.into());
     }
+
+    #[test]
+    fn point3d_approx_eq() {
+        use crate::approxeq::ApproxEq;
+        let p1: Point3D<f64> = Point3D::from([1.0_f64, 2.0, 3.0]);
+        let p2 = Point3D::from([1.0 + 1e-18, 2.0, 3.0 - 1e-18]);
+        assert!(p1.approx_eq(&p2));
+        // Accumulated rounding at non-unit magnitude stays within the default tolerance,
+        // which machine epsilon would have rejected.
+        let big: Point3D<f64> = Point3D::from([1000.0_f64, 2000.0, 3000.0]);
+        assert!(big.approx_eq(&Point3D::from([1000.0 + 2e-13, 2000.0, 3000.0 - 3e-13])));
+        // A discrepancy larger than the default tolerance is still rejected.
+        assert!(!big.approx_eq(&Point3D::from([1000.0, 2000.0, 3000.0 + 1e-9])));
+        assert!(!p1.approx_eq(&Point3D::from([1.0, 2.0, 3.5])));
+    }
+
+    #[test]
+    fn point3d_lerp() {
+        let p1: Point3D<f64> = Point3D::from([0.0_f64, 0.0, 0.0]);
+        let p2 = Point3D::from([10.0, 20.0, 30.0]);
+        assert_eq!(p1.lerp(p2, 0.5), Point3D::from([5.0, 10.0, 15.0]));
+    }
+
+    #[test]
+    fn point3d_min_max() {
+        let p1: Point3D<i32> = Point3D::from([1, 5, 9]);
+        let p2 = Point3D::from([3, 2, 7]);
+        assert_eq!(p1.min(p2), Point3D::from([1, 2, 7]));
+        assert_eq!(p1.max(p2), Point3D::from([3, 5, 9]));
+    }
+
+    // Vector3D
+
+    #[test]
+    fn vector3d_add() {
+        let v1: Vector3D<i32> = Vector3D::from([1, 2, 3]);
+        let v2 = Vector3D::from([3, 4, 5]);
+        let v3 = v1 + v2;
+        assert_eq!(v3, Vector3D::from([4, 6, 8]));
+    }
+
+    #[test]
+    fn vector3d_neg() {
+        let v1: Vector3D<i32> = Vector3D::from([1, -2, 0]);
+        let v2 = -v1;
+        assert_eq!(v2, Vector3D::from([-1, 2, 0]));
+    }
+
+    #[test]
+    fn vector3d_hypot_sq() {
+        let v: Vector3D<i32> = Vector3D::from([1, 2, 2]);
+        assert_eq!(v.hypot_sq(), Some(9));
+    }
+
+    #[test]
+    fn vector3d_to_point() {
+        let v: Vector3D<i32> = Vector3D::from([1, -2, 3]);
+        assert_eq!(v.to_point(), Point3D::from([1, -2, 3]));
+    }
+
+    // serde round-trips
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn point2d_serde_roundtrip() {
+        let p: Point2D<i32> = Point2D::from([1, -2]);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[1,-2]");
+        assert_eq!(serde_json::from_str::<Point2D<i32>>(&json).unwrap(), p);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn point3d_serde_roundtrip() {
+        let p: Point3D<i32> = Point3D::from([1, -2, 3]);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, "[1,-2,3]");
+        assert_eq!(serde_json::from_str::<Point3D<i32>>(&json).unwrap(), p);
+    }
+
+    #[test]
+    fn vector3d_mul_scalar() {
+        let v: Vector3D<i32> = Vector3D::from([1, -2, 3]);
+        assert_eq!(v * 2, Vector3D::from([2, -4, 6]));
+    }
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn vector3d_dot() {
+        let v1: Vector3D<i32> = Vector3D::from([1, 2, 3]);
+        let v2 = Vector3D::from([4, 5, 6]);
+        assert_eq!(v1.dot(&v2), 1 * 4 + 2 * 5 + 3 * 6);
+        assert_eq!(v1.checked_dot(&v2), Some(32));
+    }
+
+    #[test]
+    fn vector3d_cross() {
+        let x: Vector3D<i32> = Vector3D::from([1, 0, 0]);
+        let y = Vector3D::from([0, 1, 0]);
+        assert_eq!(x.cross(&y), Vector3D::from([0, 0, 1]));
+        assert_eq!(x.checked_cross(&y), Some(Vector3D::from([0, 0, 1])));
+    }
 }